@@ -15,6 +15,47 @@ pub struct CrxInfo {
     public_key: Vec<u8>,
     signature: Vec<u8>,
     zip_offset: usize,
+    crx_id: Vec<u8>,
+    signed_header_data: Vec<u8>,
+    rsa_public_key: Vec<u8>,
+    rsa_proofs: Vec<AsymmetricKeyProof>,
+    ecdsa_proofs: Vec<AsymmetricKeyProof>,
+}
+
+/// Error type for everything below the `#[wasm_bindgen]` boundary. Kept as a plain Rust type
+/// (rather than `JsValue` directly) so parsing and verification logic can be exercised by
+/// `cargo test` — constructing a `JsValue` panics outside an actual `wasm32` target, so none
+/// of this crate's testable logic can touch it. Public entry points convert to `JsValue` at
+/// the boundary via `From`, same as before for any caller.
+#[derive(Debug)]
+struct ParseError(String);
+
+impl ParseError {
+    fn from_str(message: &str) -> Self {
+        ParseError(message.to_string())
+    }
+}
+
+impl From<ParseError> for JsValue {
+    fn from(err: ParseError) -> JsValue {
+        JsValue::from_str(&err.0)
+    }
+}
+
+/// Builds a `ParseError` combining a static `context` with a runtime `detail` (an I/O error,
+/// or the specific values behind a validation failure). Every field read below this point
+/// parses untrusted input (the CRX/ZIP/protobuf bytes handed to the public entry points), so
+/// under the `panic-free` feature this drops `detail` and keeps only `context` — skipping the
+/// `format!`/string-formatting machinery that would otherwise get pulled into the binary for
+/// every one of these call sites.
+#[cfg(feature = "panic-free")]
+fn parse_error(context: &str, _detail: impl std::fmt::Display) -> ParseError {
+    ParseError::from_str(context)
+}
+
+#[cfg(not(feature = "panic-free"))]
+fn parse_error(context: &str, detail: impl std::fmt::Display) -> ParseError {
+    ParseError::from_str(&format!("{}: {}", context, detail))
 }
 
 #[wasm_bindgen]
@@ -38,20 +79,251 @@ impl CrxInfo {
     pub fn zip_offset(&self) -> usize {
         self.zip_offset
     }
+
+    /// 16-byte CRX3 package id (empty for CRX2, which has no `signed_header_data`).
+    #[wasm_bindgen(getter)]
+    pub fn crx_id(&self) -> Uint8Array {
+        Uint8Array::from(&self.crx_id[..])
+    }
+
+    /// The 32-character Chrome extension id derived from this package's public key: the
+    /// `public_key` field for CRX2, the first RSA proof's key for CRX3. A CRX3 package with
+    /// no RSA proof, or an unsigned ZIP, has no key to derive from and returns an error.
+    #[wasm_bindgen(getter)]
+    pub fn extension_id(&self) -> Result<String, JsValue> {
+        let key = if self.version == 2 {
+            &self.public_key
+        } else {
+            &self.rsa_public_key
+        };
+        extension_id_from_public_key(key).map_err(JsValue::from)
+    }
+}
+
+/// Derives the Chrome extension id from a DER-encoded public key: the SHA-256 digest's
+/// first 16 bytes, re-encoded one nibble at a time to the letters `a`-`p` (`0x0` -> `a`,
+/// ..., `0xf` -> `p`).
+fn extension_id_from_public_key(public_key_der: &[u8]) -> Result<String, ParseError> {
+    use sha2::{Digest, Sha256};
+
+    if public_key_der.is_empty() {
+        return Err(ParseError::from_str(
+            "No public key available to derive an extension id",
+        ));
+    }
+
+    let digest = Sha256::digest(public_key_der);
+    let mut id = String::with_capacity(32);
+    for byte in digest.iter().take(16) {
+        id.push((b'a' + (byte >> 4)) as char);
+        id.push((b'a' + (byte & 0x0f)) as char);
+    }
+    Ok(id)
+}
+
+/// A single `AsymmetricKeyProof` entry from the CRX3 `CrxFileHeader` protobuf:
+/// a public key together with the signature produced by the matching private key.
+struct AsymmetricKeyProof {
+    public_key: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+/// Reads a protobuf varint starting at `*pos`, advancing `*pos` past it.
+fn read_varint(buf: &[u8], pos: &mut usize) -> Result<u64, ParseError> {
+    let mut result: u64 = 0;
+    let mut shift = 0u32;
+    loop {
+        let byte = *buf
+            .get(*pos)
+            .ok_or_else(|| ParseError::from_str("Unexpected end of input while reading varint"))?;
+        *pos += 1;
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err(ParseError::from_str("Varint too long"));
+        }
+    }
+}
+
+/// Reads a length-delimited (wire type 2) field's payload starting at `*pos`.
+fn read_length_delimited<'a>(buf: &'a [u8], pos: &mut usize) -> Result<&'a [u8], ParseError> {
+    let len = read_varint(buf, pos)? as usize;
+    let end = pos
+        .checked_add(len)
+        .filter(|&end| end <= buf.len())
+        .ok_or_else(|| ParseError::from_str("Field length exceeds buffer"))?;
+    let slice = &buf[*pos..end];
+    *pos = end;
+    Ok(slice)
+}
+
+/// Skips a field's payload given its wire type, so unknown fields don't break parsing.
+fn skip_field(buf: &[u8], pos: &mut usize, wire_type: u8) -> Result<(), ParseError> {
+    match wire_type {
+        0 => {
+            read_varint(buf, pos)?;
+        }
+        1 => {
+            *pos = pos
+                .checked_add(8)
+                .filter(|&p| p <= buf.len())
+                .ok_or_else(|| ParseError::from_str("Truncated fixed64 field"))?;
+        }
+        2 => {
+            read_length_delimited(buf, pos)?;
+        }
+        5 => {
+            *pos = pos
+                .checked_add(4)
+                .filter(|&p| p <= buf.len())
+                .ok_or_else(|| ParseError::from_str("Truncated fixed32 field"))?;
+        }
+        other => {
+            return Err(parse_error("Unsupported protobuf wire type", other));
+        }
+    }
+    Ok(())
+}
+
+/// Decodes an `AsymmetricKeyProof` message: field 1 = `public_key`, field 2 = `signature`.
+fn parse_key_proof(buf: &[u8]) -> Result<AsymmetricKeyProof, ParseError> {
+    let mut public_key = Vec::new();
+    let mut signature = Vec::new();
+    let mut pos = 0;
+    while pos < buf.len() {
+        let tag = read_varint(buf, &mut pos)?;
+        let field_number = tag >> 3;
+        let wire_type = (tag & 0x7) as u8;
+        match (field_number, wire_type) {
+            (1, 2) => public_key = read_length_delimited(buf, &mut pos)?.to_vec(),
+            (2, 2) => signature = read_length_delimited(buf, &mut pos)?.to_vec(),
+            _ => skip_field(buf, &mut pos, wire_type)?,
+        }
+    }
+    Ok(AsymmetricKeyProof {
+        public_key,
+        signature,
+    })
+}
+
+/// Decodes a `SignedData` message: field 1 = `crx_id`.
+fn parse_signed_data(buf: &[u8]) -> Result<Vec<u8>, ParseError> {
+    let mut crx_id = Vec::new();
+    let mut pos = 0;
+    while pos < buf.len() {
+        let tag = read_varint(buf, &mut pos)?;
+        let field_number = tag >> 3;
+        let wire_type = (tag & 0x7) as u8;
+        match (field_number, wire_type) {
+            (1, 2) => crx_id = read_length_delimited(buf, &mut pos)?.to_vec(),
+            _ => skip_field(buf, &mut pos, wire_type)?,
+        }
+    }
+    Ok(crx_id)
+}
+
+/// RSA proofs, ECDSA proofs, decoded `crx_id`, and raw `signed_header_data` bytes recovered
+/// from a `CrxFileHeader` message.
+type CrxFileHeaderFields = (Vec<AsymmetricKeyProof>, Vec<AsymmetricKeyProof>, Vec<u8>, Vec<u8>);
+
+/// Decodes the top-level `CrxFileHeader` message, returning the repeated RSA and ECDSA
+/// key proofs (fields 2 and 3), the `crx_id` nested inside `signed_header_data`, and the
+/// raw bytes of `signed_header_data` itself (field 10000), needed later to reconstruct the
+/// signed payload.
+fn parse_crx_file_header(header_data: &[u8]) -> Result<CrxFileHeaderFields, ParseError> {
+    let mut rsa_proofs = Vec::new();
+    let mut ecdsa_proofs = Vec::new();
+    let mut crx_id = Vec::new();
+    let mut signed_header_data = Vec::new();
+    let mut pos = 0;
+    while pos < header_data.len() {
+        let tag = read_varint(header_data, &mut pos)?;
+        let field_number = tag >> 3;
+        let wire_type = (tag & 0x7) as u8;
+        match (field_number, wire_type) {
+            (2, 2) => {
+                let proof_data = read_length_delimited(header_data, &mut pos)?;
+                rsa_proofs.push(parse_key_proof(proof_data)?);
+            }
+            (3, 2) => {
+                let proof_data = read_length_delimited(header_data, &mut pos)?;
+                ecdsa_proofs.push(parse_key_proof(proof_data)?);
+            }
+            (10000, 2) => {
+                let raw = read_length_delimited(header_data, &mut pos)?;
+                crx_id = parse_signed_data(raw)?;
+                signed_header_data = raw.to_vec();
+            }
+            _ => skip_field(header_data, &mut pos, wire_type)?,
+        }
+    }
+    Ok((rsa_proofs, ecdsa_proofs, crx_id, signed_header_data))
+}
+
+/// Builds the "declared length exceeds remaining input" error. Under the `panic-free`
+/// feature this skips `format!` (and the formatting machinery it pulls into the binary)
+/// in favor of a static message, trading detail for a smaller `.wasm`.
+#[cfg(feature = "panic-free")]
+fn length_exceeds_input(_field: &str, _declared: usize, _remaining: usize) -> ParseError {
+    ParseError::from_str("Declared length exceeds remaining input")
+}
+
+#[cfg(not(feature = "panic-free"))]
+fn length_exceeds_input(field: &str, declared: usize, remaining: usize) -> ParseError {
+    ParseError::from_str(&format!(
+        "{} ({} bytes) exceeds remaining input ({} bytes)",
+        field, declared, remaining
+    ))
+}
+
+/// Reads exactly `len` bytes from `cursor`, rejecting `len` if it exceeds the bytes
+/// remaining in the input *before* allocating, so a crafted length field can't trigger an
+/// oversized allocation or an out-of-bounds read.
+fn read_bytes(cursor: &mut Cursor<&[u8]>, len: usize, field: &str) -> Result<Vec<u8>, ParseError> {
+    let start = cursor.position() as usize;
+    let remaining = cursor.get_ref().len().saturating_sub(start);
+    if len > remaining {
+        return Err(length_exceeds_input(field, len, remaining));
+    }
+
+    let end = start + len;
+    let slice = cursor
+        .get_ref()
+        .get(start..end)
+        .ok_or_else(|| ParseError::from_str("Internal error slicing input"))?;
+
+    let mut buf = vec![0u8; len];
+    buf.copy_from_slice(slice);
+    cursor.set_position(end as u64);
+    Ok(buf)
+}
+
+/// Returns `data` starting at `offset`, or a `ParseError` instead of panicking if
+/// `offset` is out of bounds.
+fn slice_from(data: &[u8], offset: usize) -> Result<&[u8], ParseError> {
+    data.get(offset..)
+        .ok_or_else(|| ParseError::from_str("zip_offset exceeds input length"))
 }
 
 #[wasm_bindgen]
 pub fn parse_crx(data: &[u8]) -> Result<CrxInfo, JsValue> {
+    parse_crx_impl(data).map_err(JsValue::from)
+}
+
+fn parse_crx_impl(data: &[u8]) -> Result<CrxInfo, ParseError> {
     let mut cursor = Cursor::new(data);
 
     // Read magic number ("Cr24")
     let mut magic = [0u8; 4];
     cursor
         .read_exact(&mut magic)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read magic number: {}", e)))?;
+        .map_err(|e| parse_error("Failed to read magic number", e))?;
 
     if &magic != b"Cr24" {
-        return Err(JsValue::from_str(
+        return Err(ParseError::from_str(
             "Invalid CRX file: incorrect magic number",
         ));
     }
@@ -59,7 +331,7 @@ pub fn parse_crx(data: &[u8]) -> Result<CrxInfo, JsValue> {
     // Read version
     let version = cursor
         .read_u32::<LittleEndian>()
-        .map_err(|e| JsValue::from_str(&format!("Failed to read version: {}", e)))?;
+        .map_err(|e| parse_error("Failed to read version", e))?;
 
     // Sanity check for header size based on version
     let total_size = cursor.get_ref().len();
@@ -67,18 +339,18 @@ pub fn parse_crx(data: &[u8]) -> Result<CrxInfo, JsValue> {
         2 => 16, // Magic(4) + Version(4) + PubKeyLen(4) + SigLen(4) + minimal data
         3 => 12, // Magic(4) + Version(4) + HeaderSize(4) + minimal protobuf header
         _ => {
-            return Err(JsValue::from_str(&format!(
-                "Unsupported CRX version: {}",
-                version
-            )));
+            return Err(parse_error("Unsupported CRX version", version));
         }
     };
 
     if total_size < min_header_size {
-        return Err(JsValue::from_str(&format!(
-            "CRX file too small for version {}: size {} bytes, expected at least {} bytes",
-            version, total_size, min_header_size
-        )));
+        return Err(parse_error(
+            "CRX file too small for version",
+            format_args!(
+                "{}: size {} bytes, expected at least {} bytes",
+                version, total_size, min_header_size
+            ),
+        ));
     }
 
     // Version-specific parsing
@@ -86,10 +358,7 @@ pub fn parse_crx(data: &[u8]) -> Result<CrxInfo, JsValue> {
         2 => parse_crx2(&mut cursor)?,
         3 => parse_crx3(&mut cursor)?,
         _ => {
-            return Err(JsValue::from_str(&format!(
-                "Unsupported CRX version: {}",
-                version
-            )));
+            return Err(parse_error("Unsupported CRX version", version));
         }
     };
 
@@ -99,30 +368,22 @@ pub fn parse_crx(data: &[u8]) -> Result<CrxInfo, JsValue> {
     Ok(info)
 }
 
-fn parse_crx2(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, JsValue> {
+fn parse_crx2(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, ParseError> {
     // Read public key length
     let pub_key_len = cursor
         .read_u32::<LittleEndian>()
-        .map_err(|e| JsValue::from_str(&format!("Failed to read public key length: {}", e)))?
-        as usize;
+        .map_err(|e| parse_error("Failed to read public key length", e))? as usize;
 
     // Read signature length
     let sig_len = cursor
         .read_u32::<LittleEndian>()
-        .map_err(|e| JsValue::from_str(&format!("Failed to read signature length: {}", e)))?
-        as usize;
+        .map_err(|e| parse_error("Failed to read signature length", e))? as usize;
 
     // Read public key
-    let mut public_key = vec![0u8; pub_key_len];
-    cursor
-        .read_exact(&mut public_key)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read public key: {}", e)))?;
+    let public_key = read_bytes(cursor, pub_key_len, "Public key length")?;
 
     // Read signature
-    let mut signature = vec![0u8; sig_len];
-    cursor
-        .read_exact(&mut signature)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read signature: {}", e)))?;
+    let signature = read_bytes(cursor, sig_len, "Signature length")?;
 
     // Calculate ZIP offset
     let zip_offset = 4 + 4 + 4 + 4 + pub_key_len + sig_len; // Magic + Version + PubKeyLen + SigLen + PubKey + Sig
@@ -132,14 +393,13 @@ fn parse_crx2(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, JsValue> {
     let mut zip_signature = [0u8; 4];
     if cursor
         .read(&mut zip_signature)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read ZIP signature: {}", e)))?
+        .map_err(|e| parse_error("Failed to read ZIP signature", e))?
         == 4
+        && (zip_signature[0] != 0x50 || zip_signature[1] != 0x4B)
     {
-        if zip_signature[0] != 0x50 || zip_signature[1] != 0x4B {
-            return Err(JsValue::from_str(
-                "Invalid ZIP data in CRX file (PK signature missing)",
-            ));
-        }
+        return Err(ParseError::from_str(
+            "Invalid ZIP data in CRX file (PK signature missing)",
+        ));
     }
 
     Ok(CrxInfo {
@@ -147,32 +407,45 @@ fn parse_crx2(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, JsValue> {
         public_key,
         signature,
         zip_offset,
+        crx_id: Vec::new(),
+        signed_header_data: Vec::new(),
+        rsa_public_key: Vec::new(),
+        rsa_proofs: Vec::new(),
+        ecdsa_proofs: Vec::new(),
     })
 }
 
 // Improved Rust code for parsing CRX3
-fn parse_crx3(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, JsValue> {
+fn parse_crx3(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, ParseError> {
     cursor.set_position(8); // Already read magic number and version
 
     // Read header size (4 bytes)
     let mut header_size_bytes = [0u8; 4];
     cursor
         .read_exact(&mut header_size_bytes)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read header size: {}", e)))?;
+        .map_err(|e| parse_error("Failed to read header size", e))?;
     let header_size = u32::from_le_bytes(header_size_bytes) as usize;
 
     // Read header data
-    let mut header_data = vec![0u8; header_size];
-    cursor
-        .read_exact(&mut header_data)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read header data: {}", e)))?;
+    let header_data = read_bytes(cursor, header_size, "Header size")?;
 
     // Calculate ZIP offset
     let zip_offset = 12 + header_size; // 4 bytes magic + 4 bytes version + 4 bytes header size + header data
-    // Here we might parse the protobuf header to extract public key and signature
-    // For simplicity, we'll use empty values if you don't need these fields
-    let public_key = Vec::new();
-    let signature = Vec::new();
+
+    // Decode the CrxFileHeader protobuf to recover the key proofs and crx_id. The full proof
+    // lists are kept on CrxInfo (not just the first of each) so verify_signature can check
+    // every proof instead of silently ignoring all but the first.
+    let (rsa_proofs, ecdsa_proofs, crx_id, signed_header_data) =
+        parse_crx_file_header(&header_data)?;
+    let (public_key, signature) = rsa_proofs
+        .first()
+        .or_else(|| ecdsa_proofs.first())
+        .map(|proof| (proof.public_key.clone(), proof.signature.clone()))
+        .unwrap_or_default();
+    let rsa_public_key = rsa_proofs
+        .first()
+        .map(|proof| proof.public_key.clone())
+        .unwrap_or_default();
 
     // Optional: Verify ZIP signature
     let current_position = cursor.position() as usize;
@@ -183,14 +456,13 @@ fn parse_crx3(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, JsValue> {
     let mut zip_signature = [0u8; 4];
     if cursor
         .read(&mut zip_signature)
-        .map_err(|e| JsValue::from_str(&format!("Failed to read ZIP signature: {}", e)))?
+        .map_err(|e| parse_error("Failed to read ZIP signature", e))?
         == 4
+        && (zip_signature[0] != 0x50 || zip_signature[1] != 0x4B)
     {
-        if zip_signature[0] != 0x50 || zip_signature[1] != 0x4B {
-            return Err(JsValue::from_str(
-                "Invalid ZIP data in CRX file (PK signature missing)",
-            ));
-        }
+        return Err(ParseError::from_str(
+            "Invalid ZIP data in CRX file (PK signature missing)",
+        ));
     }
 
     Ok(CrxInfo {
@@ -198,55 +470,800 @@ fn parse_crx3(cursor: &mut Cursor<&[u8]>) -> Result<CrxInfo, JsValue> {
         public_key,
         signature,
         zip_offset,
+        crx_id,
+        signed_header_data,
+        rsa_public_key,
+        rsa_proofs,
+        ecdsa_proofs,
     })
 }
 
+/// Common surface shared by every package format this crate understands, so callers and
+/// future formats don't need to go through version-specific functions.
+///
+/// Named distinctly from `CrxInfo`'s wasm-bindgen getters (`version()`/`zip_offset()`, which
+/// are inherent impl methods) so the two can't shadow one another and silently resolve to the
+/// wrong one.
+trait Package {
+    fn package_version(&self) -> u32;
+    fn package_zip_offset(&self) -> usize;
+    fn zip_reader<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], ParseError>;
+}
+
+impl Package for CrxInfo {
+    fn package_version(&self) -> u32 {
+        self.version
+    }
+
+    fn package_zip_offset(&self) -> usize {
+        self.zip_offset
+    }
+
+    fn zip_reader<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], ParseError> {
+        slice_from(data, self.package_zip_offset())
+    }
+}
+
+/// Magic that identifies an unsigned ZIP archive's first local file header, e.g. a plain
+/// `.zip` or a Firefox `.xpi`.
+const ZIP_MAGIC: [u8; 4] = LOCAL_FILE_HEADER_SIGNATURE;
+
+/// CRX magic number ("Cr24").
+const CRX_MAGIC: [u8; 4] = *b"Cr24";
+
+/// Sentinel `version()` for an unsigned ZIP/XPI package opened without a CRX wrapper.
+const UNSIGNED_ZIP_VERSION: u32 = 0;
+
+/// Detects the package format from its leading bytes and parses it through the
+/// corresponding backend: `Cr24` dispatches to the existing CRX2/CRX3 parsers, while a
+/// bare ZIP local file header is treated as an unsigned archive (Firefox `.xpi` or a plain
+/// extension zip) with no key, signature, or header to skip.
+#[wasm_bindgen]
+pub fn open(data: &[u8]) -> Result<CrxInfo, JsValue> {
+    if data.len() >= 4 && data[0..4] == CRX_MAGIC {
+        parse_crx(data)
+    } else if data.len() >= 4 && data[0..4] == ZIP_MAGIC {
+        Ok(CrxInfo {
+            version: UNSIGNED_ZIP_VERSION,
+            public_key: Vec::new(),
+            signature: Vec::new(),
+            zip_offset: 0,
+            crx_id: Vec::new(),
+            signed_header_data: Vec::new(),
+            rsa_public_key: Vec::new(),
+            rsa_proofs: Vec::new(),
+            ecdsa_proofs: Vec::new(),
+        })
+    } else {
+        Err(JsValue::from_str(
+            "Unrecognized package format (expected a CRX \"Cr24\" header or a ZIP local file header)",
+        ))
+    }
+}
+
 #[wasm_bindgen]
 pub fn extract_zip_data(data: &[u8]) -> Result<Uint8Array, JsValue> {
-    let crx_info = parse_crx(data)?;
+    let package = open(data)?;
 
     // Extract the ZIP portion of the file starting at the calculated offset
-    let zip_data = &data[crx_info.zip_offset..];
+    let zip_data = package.zip_reader(data)?;
     Ok(Uint8Array::from(zip_data))
 }
 
-fn verify_crx_info(info: &CrxInfo) -> Result<(), JsValue> {
+/// Parses `data` and returns the Chrome extension id for the resulting package.
+#[wasm_bindgen]
+pub fn extension_id(data: &[u8]) -> Result<String, JsValue> {
+    let crx_info = open(data)?;
+    crx_info.extension_id()
+}
+
+/// Magic prefix prepended to the signed payload, as defined by the CRX3 format.
+const CRX3_SIGNED_DATA_MAGIC: &[u8] = b"CRX3 SignedData\0";
+
+/// Verifies a CRX3 package's signature.
+///
+/// Reconstructs the signed message (magic, little-endian length of `signed_header_data`,
+/// `signed_header_data`, then the ZIP archive) and checks it against every `AsymmetricKeyProof`
+/// in the header (RSA-PKCS1-SHA256 for the RSA proof list, ECDSA-P256-SHA256 for the ECDSA
+/// one) — a webstore-signed CRX3 typically carries more than one proof (e.g. a developer-key
+/// proof plus a "verified contents" proof), and the package is valid if any one of them checks
+/// out.
+#[wasm_bindgen]
+pub fn verify_signature(data: &[u8]) -> Result<bool, JsValue> {
+    let info = open(data)?;
+
+    if info.package_version() != 3 {
+        return Err(JsValue::from_str(
+            "Signature verification is only implemented for CRX3 packages",
+        ));
+    }
+
+    if info.rsa_proofs.is_empty() && info.ecdsa_proofs.is_empty() {
+        return Err(JsValue::from_str(
+            "CRX3 header contains no RSA or ECDSA key proof to verify",
+        ));
+    }
+
+    let zip_data = info.zip_reader(data)?;
+
+    let mut signed_message = Vec::with_capacity(
+        CRX3_SIGNED_DATA_MAGIC.len() + 4 + info.signed_header_data.len() + zip_data.len(),
+    );
+    signed_message.extend_from_slice(CRX3_SIGNED_DATA_MAGIC);
+    signed_message.extend_from_slice(&(info.signed_header_data.len() as u32).to_le_bytes());
+    signed_message.extend_from_slice(&info.signed_header_data);
+    signed_message.extend_from_slice(zip_data);
+
+    Ok(any_proof_verifies(&info.rsa_proofs, &info.ecdsa_proofs, &signed_message))
+}
+
+/// Checks every proof in `rsa_proofs` (RSA-PKCS1-SHA256) and `ecdsa_proofs` (ECDSA-P256-SHA256)
+/// against `message`, returning `true` as soon as one verifies. A proof that fails to parse
+/// (malformed key or signature) is treated the same as one that fails to verify, so one bad
+/// proof can't prevent a later, valid one from being checked.
+fn any_proof_verifies(
+    rsa_proofs: &[AsymmetricKeyProof],
+    ecdsa_proofs: &[AsymmetricKeyProof],
+    message: &[u8],
+) -> bool {
+    rsa_proofs
+        .iter()
+        .any(|proof| verify_rsa_proof(&proof.public_key, &proof.signature, message).unwrap_or(false))
+        || ecdsa_proofs
+            .iter()
+            .any(|proof| verify_ecdsa_proof(&proof.public_key, &proof.signature, message).unwrap_or(false))
+}
+
+/// Verifies an RSA PKCS#1 v1.5 / SHA-256 signature over `message` using a DER-encoded
+/// SubjectPublicKeyInfo.
+fn verify_rsa_proof(public_key_der: &[u8], signature: &[u8], message: &[u8]) -> Result<bool, ParseError> {
+    use rsa::pkcs1v15::{Signature, VerifyingKey};
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::signature::Verifier;
+    use rsa::RsaPublicKey;
+    use sha2::Sha256;
+
+    let public_key = RsaPublicKey::from_public_key_der(public_key_der)
+        .map_err(|e| parse_error("Malformed RSA public key", e))?;
+    let verifying_key = VerifyingKey::<Sha256>::new(public_key);
+    let signature =
+        Signature::try_from(signature).map_err(|e| parse_error("Malformed RSA signature", e))?;
+
+    Ok(verifying_key.verify(message, &signature).is_ok())
+}
+
+/// Verifies an ECDSA P-256 / SHA-256 signature over `message` using a DER-encoded
+/// SubjectPublicKeyInfo.
+fn verify_ecdsa_proof(public_key_der: &[u8], signature: &[u8], message: &[u8]) -> Result<bool, ParseError> {
+    use ecdsa::signature::Verifier;
+    use p256::ecdsa::{Signature, VerifyingKey};
+    use p256::pkcs8::DecodePublicKey;
+
+    let verifying_key = VerifyingKey::from_public_key_der(public_key_der)
+        .map_err(|e| parse_error("Malformed ECDSA public key", e))?;
+    let signature = Signature::from_der(signature)
+        .map_err(|e| parse_error("Malformed ECDSA signature", e))?;
+
+    Ok(verifying_key.verify(message, &signature).is_ok())
+}
+
+fn verify_crx_info(info: &CrxInfo) -> Result<(), ParseError> {
     // For CRX2 files, we expect non-empty public key and signature
     if info.version == 2 {
         if info.public_key.is_empty() {
-            return Err(JsValue::from_str(
+            return Err(ParseError::from_str(
                 "Invalid CRX2 structure: Public key is empty",
             ));
         }
         if info.signature.is_empty() {
-            return Err(JsValue::from_str(
+            return Err(ParseError::from_str(
                 "Invalid CRX2 structure: Signature is empty",
             ));
         }
     }
-    // For CRX3 files, we expect empty public_key and signature in our struct
-    // as they're stored differently in the protobuf header
-    else if info.version == 3 {
-        if !info.public_key.is_empty() {
-            return Err(JsValue::from_str(
-                "Invalid CRX3 structure: Public key should be empty",
-            ));
+    // Verify the zip_offset is reasonable (greater than minimum header size)
+    let min_offset = if info.version == 2 { 16 } else { 12 };
+    if info.zip_offset < min_offset {
+        return Err(parse_error(
+            "Invalid zip offset",
+            format_args!("{} (should be at least {})", info.zip_offset, min_offset),
+        ));
+    }
+
+    Ok(())
+}
+
+/// One entry in a ZIP archive's central directory.
+struct ZipEntry {
+    name: String,
+    compression_method: u16,
+    crc32: u32,
+    compressed_size: u32,
+    uncompressed_size: u32,
+    local_header_offset: u32,
+}
+
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: [u8; 4] = [0x50, 0x4b, 0x05, 0x06];
+const CENTRAL_DIRECTORY_FILE_HEADER_SIGNATURE: [u8; 4] = [0x50, 0x4b, 0x01, 0x02];
+const LOCAL_FILE_HEADER_SIGNATURE: [u8; 4] = [0x50, 0x4b, 0x03, 0x04];
+
+/// Scans backwards from the end of `data` for the End Of Central Directory signature,
+/// returning its offset. The EOCD record is fixed size (22 bytes) plus up to a 64 KiB
+/// comment, so the search only needs to cover that trailing window.
+fn find_end_of_central_directory(data: &[u8]) -> Result<usize, ParseError> {
+    const EOCD_FIXED_SIZE: usize = 22;
+    const MAX_COMMENT_LEN: usize = 65535;
+
+    if data.len() < EOCD_FIXED_SIZE {
+        return Err(ParseError::from_str("ZIP data too small to contain an End Of Central Directory record"));
+    }
+
+    let search_start = data.len().saturating_sub(EOCD_FIXED_SIZE + MAX_COMMENT_LEN);
+    for offset in (search_start..=data.len() - 4).rev() {
+        if data[offset..offset + 4] == END_OF_CENTRAL_DIRECTORY_SIGNATURE {
+            return Ok(offset);
         }
-        if !info.signature.is_empty() {
-            return Err(JsValue::from_str(
-                "Invalid CRX3 structure: Signature should be empty",
+    }
+
+    Err(ParseError::from_str("End Of Central Directory record not found (not a valid ZIP archive)"))
+}
+
+/// Parses the central directory of a ZIP archive, returning one `ZipEntry` per file.
+fn parse_central_directory(data: &[u8]) -> Result<Vec<ZipEntry>, ParseError> {
+    let eocd_offset = find_end_of_central_directory(data)?;
+    let mut cursor = Cursor::new(data);
+
+    cursor.set_position(eocd_offset as u64 + 10);
+    let total_entries = cursor
+        .read_u16::<LittleEndian>()
+        .map_err(|e| parse_error("Failed to read central directory entry count", e))?
+        as usize;
+
+    cursor.set_position(eocd_offset as u64 + 16);
+    let central_directory_offset = cursor
+        .read_u32::<LittleEndian>()
+        .map_err(|e| parse_error("Failed to read central directory offset", e))?
+        as u64;
+
+    cursor.set_position(central_directory_offset);
+    let mut entries = Vec::with_capacity(total_entries);
+    for _ in 0..total_entries {
+        let mut signature = [0u8; 4];
+        cursor
+            .read_exact(&mut signature)
+            .map_err(|e| parse_error("Failed to read central directory file header", e))?;
+        if signature != CENTRAL_DIRECTORY_FILE_HEADER_SIGNATURE {
+            return Err(ParseError::from_str("Invalid central directory file header signature"));
+        }
+
+        cursor.set_position(cursor.position() + 4); // version made by + version needed to extract
+        cursor.set_position(cursor.position() + 2); // general purpose bit flag
+        let compression_method = cursor
+            .read_u16::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read compression method", e))?;
+        cursor.set_position(cursor.position() + 4); // last mod file time + date
+        let crc32 = cursor
+            .read_u32::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read CRC-32", e))?;
+        let compressed_size = cursor
+            .read_u32::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read compressed size", e))?;
+        let uncompressed_size = cursor
+            .read_u32::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read uncompressed size", e))?;
+        let name_len = cursor
+            .read_u16::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read file name length", e))?
+            as usize;
+        let extra_len = cursor
+            .read_u16::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read extra field length", e))?
+            as usize;
+        let comment_len = cursor
+            .read_u16::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read file comment length", e))?
+            as usize;
+        cursor.set_position(cursor.position() + 8); // disk number start + internal attrs + external attrs
+        let local_header_offset = cursor
+            .read_u32::<LittleEndian>()
+            .map_err(|e| parse_error("Failed to read local header offset", e))?;
+
+        let mut name_bytes = vec![0u8; name_len];
+        cursor
+            .read_exact(&mut name_bytes)
+            .map_err(|e| parse_error("Failed to read file name", e))?;
+        let name = String::from_utf8_lossy(&name_bytes).into_owned();
+
+        cursor.set_position(cursor.position() + extra_len as u64 + comment_len as u64);
+
+        entries.push(ZipEntry {
+            name,
+            compression_method,
+            crc32,
+            compressed_size,
+            uncompressed_size,
+            local_header_offset,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Locates `entry`'s local file header and returns the slice of `data` holding its
+/// (possibly compressed) file data.
+fn locate_entry_data<'a>(data: &'a [u8], entry: &ZipEntry) -> Result<&'a [u8], ParseError> {
+    let mut cursor = Cursor::new(data);
+    cursor.set_position(entry.local_header_offset as u64);
+
+    let mut signature = [0u8; 4];
+    cursor
+        .read_exact(&mut signature)
+        .map_err(|e| parse_error("Failed to read local file header", e))?;
+    if signature != LOCAL_FILE_HEADER_SIGNATURE {
+        return Err(ParseError::from_str("Invalid local file header signature"));
+    }
+
+    cursor.set_position(cursor.position() + 22); // version, flags, method, time, date, crc32, sizes
+    let name_len = cursor
+        .read_u16::<LittleEndian>()
+        .map_err(|e| parse_error("Failed to read local file name length", e))?
+        as u64;
+    let extra_len = cursor
+        .read_u16::<LittleEndian>()
+        .map_err(|e| parse_error("Failed to read local extra field length", e))?
+        as u64;
+
+    let data_start = cursor.position() + name_len + extra_len;
+    let data_end = data_start
+        .checked_add(entry.compressed_size as u64)
+        .ok_or_else(|| ParseError::from_str("Compressed size overflows archive bounds"))?;
+
+    data.get(data_start as usize..data_end as usize)
+        .ok_or_else(|| parse_error("Entry data extends past end of archive", &entry.name))
+}
+
+/// Caps how much file data a single entry may inflate (or be stored as), so a crafted
+/// archive with a small compressed payload and a huge declared/actual uncompressed size
+/// (a "zip bomb") can't force an unbounded allocation.
+const MAX_ENTRY_SIZE: u64 = 512 * 1024 * 1024;
+
+/// Builds the "entry too large" error. Under the `panic-free` feature this skips `format!`
+/// in favor of a static message, trading detail for a smaller `.wasm`.
+#[cfg(feature = "panic-free")]
+fn entry_too_large(_name: &str) -> ParseError {
+    ParseError::from_str("Entry data exceeds the maximum allowed size")
+}
+
+#[cfg(not(feature = "panic-free"))]
+fn entry_too_large(name: &str) -> ParseError {
+    ParseError::from_str(&format!(
+        "Entry \"{}\" exceeds the maximum allowed size of {} bytes",
+        name, MAX_ENTRY_SIZE
+    ))
+}
+
+/// Inflates (or copies, if stored) `entry`'s file data and checks it against the CRC-32
+/// recorded in the central directory.
+fn read_and_verify_entry(data: &[u8], entry: &ZipEntry) -> Result<Vec<u8>, ParseError> {
+    let compressed = locate_entry_data(data, entry)?;
+
+    let decompressed = match entry.compression_method {
+        0 => {
+            if compressed.len() as u64 > MAX_ENTRY_SIZE {
+                return Err(entry_too_large(&entry.name));
+            }
+            compressed.to_vec()
+        }
+        8 => {
+            let mut decoder = flate2::read::DeflateDecoder::new(compressed);
+            let capacity = (entry.uncompressed_size as u64).min(MAX_ENTRY_SIZE) as usize;
+            let mut out = Vec::with_capacity(capacity);
+            decoder
+                .by_ref()
+                .take(MAX_ENTRY_SIZE + 1)
+                .read_to_end(&mut out)
+                .map_err(|e| parse_error("Failed to inflate entry", format_args!("\"{}\": {}", entry.name, e)))?;
+            if out.len() as u64 > MAX_ENTRY_SIZE {
+                return Err(entry_too_large(&entry.name));
+            }
+            out
+        }
+        other => {
+            return Err(parse_error(
+                "Unsupported compression method for entry",
+                format_args!("{} (\"{}\")", other, entry.name),
             ));
         }
+    };
+
+    let actual_crc32 = crc32fast::hash(&decompressed);
+    if actual_crc32 != entry.crc32 {
+        return Err(parse_error(
+            "CRC-32 mismatch for entry",
+            format_args!("\"{}\": expected {:#010x}, got {:#010x}", entry.name, entry.crc32, actual_crc32),
+        ));
     }
 
-    // Verify the zip_offset is reasonable (greater than minimum header size)
-    let min_offset = if info.version == 2 { 16 } else { 12 };
-    if info.zip_offset < min_offset {
-        return Err(JsValue::from_str(&format!(
-            "Invalid zip offset: {} (should be at least {})",
-            info.zip_offset, min_offset
-        )));
+    Ok(decompressed)
+}
+
+/// Lists the files in a CRX package's ZIP archive as an array of
+/// `{ name, compressionMethod, crc32, compressedSize, uncompressedSize }` objects.
+#[wasm_bindgen]
+pub fn list_entries(data: &[u8]) -> Result<JsValue, JsValue> {
+    let package = open(data)?;
+    let zip_data = package.zip_reader(data)?;
+    let entries = parse_central_directory(zip_data)?;
+
+    let result = js_sys::Array::new();
+    for entry in &entries {
+        let obj = js_sys::Object::new();
+        js_sys::Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str(&entry.name))?;
+        js_sys::Reflect::set(
+            &obj,
+            &JsValue::from_str("compressionMethod"),
+            &JsValue::from_f64(entry.compression_method as f64),
+        )?;
+        js_sys::Reflect::set(&obj, &JsValue::from_str("crc32"), &JsValue::from_f64(entry.crc32 as f64))?;
+        js_sys::Reflect::set(
+            &obj,
+            &JsValue::from_str("compressedSize"),
+            &JsValue::from_f64(entry.compressed_size as f64),
+        )?;
+        js_sys::Reflect::set(
+            &obj,
+            &JsValue::from_str("uncompressedSize"),
+            &JsValue::from_f64(entry.uncompressed_size as f64),
+        )?;
+        result.push(&obj);
     }
 
-    Ok(())
+    Ok(result.into())
+}
+
+/// Extracts and inflates a single named file from a CRX package's ZIP archive,
+/// verifying its CRC-32 against the central directory before returning it.
+#[wasm_bindgen]
+pub fn extract_file(data: &[u8], name: &str) -> Result<Uint8Array, JsValue> {
+    let package = open(data)?;
+    let zip_data = package.zip_reader(data)?;
+    let entries = parse_central_directory(zip_data)?;
+
+    let entry = entries
+        .iter()
+        .find(|entry| entry.name == name)
+        .ok_or_else(|| JsValue::from(parse_error("No entry named in archive", name)))?;
+
+    let decompressed = read_and_verify_entry(zip_data, entry)?;
+    Ok(Uint8Array::from(&decompressed[..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_varint(mut value: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                byte |= 0x80;
+            }
+            out.push(byte);
+            if value == 0 {
+                break;
+            }
+        }
+        out
+    }
+
+    fn encode_length_delimited(field_number: u64, payload: &[u8]) -> Vec<u8> {
+        let mut out = encode_varint((field_number << 3) | 2);
+        out.extend(encode_varint(payload.len() as u64));
+        out.extend_from_slice(payload);
+        out
+    }
+
+    fn encode_key_proof(public_key: &[u8], signature: &[u8]) -> Vec<u8> {
+        let mut out = encode_length_delimited(1, public_key);
+        out.extend(encode_length_delimited(2, signature));
+        out
+    }
+
+    #[test]
+    fn read_varint_single_byte() {
+        let buf = [0x01];
+        let mut pos = 0;
+        assert_eq!(read_varint(&buf, &mut pos).unwrap(), 1);
+        assert_eq!(pos, 1);
+    }
+
+    #[test]
+    fn read_varint_multi_byte() {
+        let buf = [0xac, 0x02]; // 300
+        let mut pos = 0;
+        assert_eq!(read_varint(&buf, &mut pos).unwrap(), 300);
+        assert_eq!(pos, 2);
+    }
+
+    #[test]
+    fn read_varint_rejects_truncated_input() {
+        let buf = [0x80]; // continuation bit set, no following byte
+        let mut pos = 0;
+        assert!(read_varint(&buf, &mut pos).is_err());
+    }
+
+    #[test]
+    fn read_length_delimited_extracts_payload() {
+        let buf = [0x03, b'a', b'b', b'c'];
+        let mut pos = 0;
+        assert_eq!(read_length_delimited(&buf, &mut pos).unwrap(), b"abc");
+        assert_eq!(pos, 4);
+    }
+
+    #[test]
+    fn read_length_delimited_rejects_oversized_length() {
+        let buf = [0x05, b'a', b'b'];
+        let mut pos = 0;
+        assert!(read_length_delimited(&buf, &mut pos).is_err());
+    }
+
+    #[test]
+    fn parse_key_proof_decodes_public_key_and_signature() {
+        let buf = encode_key_proof(b"pubkey", b"sig");
+        let proof = parse_key_proof(&buf).unwrap();
+        assert_eq!(proof.public_key, b"pubkey");
+        assert_eq!(proof.signature, b"sig");
+    }
+
+    #[test]
+    fn parse_signed_data_decodes_crx_id() {
+        let buf = encode_length_delimited(1, b"0123456789abcdef");
+        assert_eq!(parse_signed_data(&buf).unwrap(), b"0123456789abcdef");
+    }
+
+    #[test]
+    fn parse_crx_file_header_collects_proofs_and_signed_header() {
+        let signed_data = encode_length_delimited(1, b"0123456789abcdef");
+        let mut header = Vec::new();
+        header.extend(encode_length_delimited(2, &encode_key_proof(b"rsa-key", b"rsa-sig")));
+        header.extend(encode_length_delimited(10000, &signed_data));
+
+        let (rsa_proofs, ecdsa_proofs, crx_id, signed_header_data) =
+            parse_crx_file_header(&header).unwrap();
+        assert_eq!(rsa_proofs.len(), 1);
+        assert_eq!(rsa_proofs[0].public_key, b"rsa-key");
+        assert_eq!(rsa_proofs[0].signature, b"rsa-sig");
+        assert!(ecdsa_proofs.is_empty());
+        assert_eq!(crx_id, b"0123456789abcdef");
+        assert_eq!(signed_header_data, signed_data);
+    }
+
+    #[test]
+    fn read_bytes_rejects_length_exceeding_remaining_input() {
+        let data = [1u8, 2, 3];
+        let mut cursor = Cursor::new(&data[..]);
+        assert!(read_bytes(&mut cursor, 10, "Test field").is_err());
+    }
+
+    #[test]
+    fn read_bytes_reads_exact_slice_and_advances_cursor() {
+        let data = [1u8, 2, 3, 4];
+        let mut cursor = Cursor::new(&data[..]);
+        let bytes = read_bytes(&mut cursor, 2, "Test field").unwrap();
+        assert_eq!(bytes, vec![1, 2]);
+        assert_eq!(cursor.position(), 2);
+    }
+
+    fn rsa_key_and_signature(message: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        use rsa::pkcs1v15::SigningKey;
+        use rsa::pkcs8::EncodePublicKey;
+        use rsa::signature::{RandomizedSigner, SignatureEncoding};
+        use rsa::RsaPrivateKey;
+        use sha2::Sha256;
+
+        let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        let public_key_der = private_key
+            .to_public_key()
+            .to_public_key_der()
+            .unwrap()
+            .into_vec();
+        let signing_key = SigningKey::<Sha256>::new(private_key);
+        let signature = signing_key.sign_with_rng(&mut rand::thread_rng(), message);
+        (public_key_der, signature.to_vec())
+    }
+
+    #[test]
+    fn verify_rsa_proof_accepts_matching_signature() {
+        let message = b"crx3 signed payload";
+        let (public_key_der, signature) = rsa_key_and_signature(message);
+        assert!(verify_rsa_proof(&public_key_der, &signature, message).unwrap());
+    }
+
+    #[test]
+    fn verify_rsa_proof_rejects_tampered_message() {
+        let message = b"crx3 signed payload";
+        let (public_key_der, signature) = rsa_key_and_signature(message);
+        assert!(!verify_rsa_proof(&public_key_der, &signature, b"tampered payload").unwrap());
+    }
+
+    fn ecdsa_key_and_signature(message: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        use ecdsa::signature::Signer;
+        use p256::ecdsa::{Signature, SigningKey};
+        use p256::pkcs8::EncodePublicKey;
+
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let public_key_der = signing_key
+            .verifying_key()
+            .to_public_key_der()
+            .unwrap()
+            .into_vec();
+        let signature: Signature = signing_key.sign(message);
+        (public_key_der, signature.to_der().to_bytes().to_vec())
+    }
+
+    #[test]
+    fn verify_ecdsa_proof_accepts_matching_signature() {
+        let message = b"crx3 signed payload";
+        let (public_key_der, signature) = ecdsa_key_and_signature(message);
+        assert!(verify_ecdsa_proof(&public_key_der, &signature, message).unwrap());
+    }
+
+    #[test]
+    fn verify_ecdsa_proof_rejects_tampered_message() {
+        let message = b"crx3 signed payload";
+        let (public_key_der, signature) = ecdsa_key_and_signature(message);
+        assert!(!verify_ecdsa_proof(&public_key_der, &signature, b"tampered payload").unwrap());
+    }
+
+    fn zip_fixture() -> Vec<u8> {
+        let contents = b"hello world";
+        let crc = crc32fast::hash(contents);
+        let name = b"hello.txt";
+
+        let mut local_header = Vec::new();
+        local_header.extend(LOCAL_FILE_HEADER_SIGNATURE);
+        local_header.extend([20, 0]); // version needed to extract
+        local_header.extend([0, 0]); // general purpose bit flag
+        local_header.extend([0, 0]); // compression method (stored)
+        local_header.extend([0, 0, 0, 0]); // last mod time + date
+        local_header.extend(crc.to_le_bytes());
+        local_header.extend((contents.len() as u32).to_le_bytes()); // compressed size
+        local_header.extend((contents.len() as u32).to_le_bytes()); // uncompressed size
+        local_header.extend((name.len() as u16).to_le_bytes());
+        local_header.extend(0u16.to_le_bytes()); // extra field length
+        local_header.extend(name);
+        local_header.extend(contents);
+
+        let central_directory_offset = local_header.len() as u32;
+        let mut central_directory = Vec::new();
+        central_directory.extend(CENTRAL_DIRECTORY_FILE_HEADER_SIGNATURE);
+        central_directory.extend([20, 0]); // version made by
+        central_directory.extend([20, 0]); // version needed to extract
+        central_directory.extend([0, 0]); // general purpose bit flag
+        central_directory.extend([0, 0]); // compression method (stored)
+        central_directory.extend([0, 0, 0, 0]); // last mod time + date
+        central_directory.extend(crc.to_le_bytes());
+        central_directory.extend((contents.len() as u32).to_le_bytes()); // compressed size
+        central_directory.extend((contents.len() as u32).to_le_bytes()); // uncompressed size
+        central_directory.extend((name.len() as u16).to_le_bytes());
+        central_directory.extend(0u16.to_le_bytes()); // extra field length
+        central_directory.extend(0u16.to_le_bytes()); // file comment length
+        central_directory.extend(0u16.to_le_bytes()); // disk number start
+        central_directory.extend(0u16.to_le_bytes()); // internal file attributes
+        central_directory.extend(0u32.to_le_bytes()); // external file attributes
+        central_directory.extend(0u32.to_le_bytes()); // local header offset
+        central_directory.extend(name);
+
+        let mut eocd = Vec::new();
+        eocd.extend(END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        eocd.extend(0u16.to_le_bytes()); // disk number
+        eocd.extend(0u16.to_le_bytes()); // disk with central directory
+        eocd.extend(1u16.to_le_bytes()); // entries on this disk
+        eocd.extend(1u16.to_le_bytes()); // total entries
+        eocd.extend((central_directory.len() as u32).to_le_bytes());
+        eocd.extend(central_directory_offset.to_le_bytes());
+        eocd.extend(0u16.to_le_bytes()); // comment length
+
+        let mut archive = local_header;
+        archive.extend(central_directory);
+        archive.extend(eocd);
+        archive
+    }
+
+    #[test]
+    fn find_end_of_central_directory_locates_signature() {
+        let archive = zip_fixture();
+        let expected = archive.len() - 22;
+        assert_eq!(find_end_of_central_directory(&archive).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_central_directory_recovers_entry_metadata() {
+        let archive = zip_fixture();
+        let entries = parse_central_directory(&archive).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "hello.txt");
+        assert_eq!(entries[0].compression_method, 0);
+        assert_eq!(entries[0].uncompressed_size, 11);
+    }
+
+    #[test]
+    fn read_and_verify_entry_returns_stored_contents() {
+        let archive = zip_fixture();
+        let entries = parse_central_directory(&archive).unwrap();
+        let decompressed = read_and_verify_entry(&archive, &entries[0]).unwrap();
+        assert_eq!(decompressed, b"hello world");
+    }
+
+    #[test]
+    fn read_and_verify_entry_rejects_crc_mismatch() {
+        let mut archive = zip_fixture();
+        let last_content_byte = archive.iter().position(|&b| b == b'd').unwrap();
+        archive[last_content_byte] = b'x';
+        let entries = parse_central_directory(&archive).unwrap();
+        assert!(read_and_verify_entry(&archive, &entries[0]).is_err());
+    }
+
+    /// Builds a full CRX3 file: magic, version, header (an arbitrary list of RSA proofs plus
+    /// `signed_header_data`), then `zip_data` verbatim.
+    fn crx3_fixture(rsa_proofs: &[(Vec<u8>, Vec<u8>)], zip_data: &[u8]) -> Vec<u8> {
+        let signed_header_data = encode_length_delimited(1, b"0123456789abcdef"); // crx_id
+
+        let mut header = Vec::new();
+        for (public_key, signature) in rsa_proofs {
+            header.extend(encode_length_delimited(2, &encode_key_proof(public_key, signature)));
+        }
+        header.extend(encode_length_delimited(10000, &signed_header_data));
+
+        let mut file = Vec::new();
+        file.extend(b"Cr24");
+        file.extend(3u32.to_le_bytes());
+        file.extend((header.len() as u32).to_le_bytes());
+        file.extend(&header);
+        file.extend(zip_data);
+        file
+    }
+
+    /// The exact message `verify_signature` reconstructs and checks proofs against, so tests
+    /// can sign the same bytes the real entry point will verify.
+    fn crx3_signed_message(zip_data: &[u8]) -> Vec<u8> {
+        let signed_header_data = encode_length_delimited(1, b"0123456789abcdef");
+        let mut message = Vec::new();
+        message.extend_from_slice(CRX3_SIGNED_DATA_MAGIC);
+        message.extend_from_slice(&(signed_header_data.len() as u32).to_le_bytes());
+        message.extend_from_slice(&signed_header_data);
+        message.extend_from_slice(zip_data);
+        message
+    }
+
+    #[test]
+    fn verify_signature_accepts_a_valid_developer_key_proof() {
+        let zip_data = zip_fixture();
+        let (public_key, signature) = rsa_key_and_signature(&crx3_signed_message(&zip_data));
+        let file = crx3_fixture(&[(public_key, signature)], &zip_data);
+        assert!(verify_signature(&file).unwrap());
+    }
+
+    #[test]
+    fn verify_signature_checks_every_proof_not_just_the_first() {
+        let zip_data = zip_fixture();
+        let (public_key, signature) = rsa_key_and_signature(&crx3_signed_message(&zip_data));
+        // A webstore-signed CRX3 carries more than one proof (e.g. a "verified contents" proof
+        // alongside the developer-key proof). Put a proof that doesn't verify first to make sure
+        // it doesn't stop the real one after it from being checked.
+        let bogus = (b"not a der-encoded key".to_vec(), b"not a signature".to_vec());
+        let file = crx3_fixture(&[bogus, (public_key, signature)], &zip_data);
+        assert!(verify_signature(&file).unwrap());
+    }
+
+    #[test]
+    fn verify_signature_rejects_when_no_proof_verifies() {
+        let zip_data = zip_fixture();
+        let (public_key, _) = rsa_key_and_signature(&crx3_signed_message(&zip_data));
+        let (_, wrong_signature) = rsa_key_and_signature(b"a different message entirely");
+        let file = crx3_fixture(&[(public_key, wrong_signature)], &zip_data);
+        assert!(!verify_signature(&file).unwrap());
+    }
 }